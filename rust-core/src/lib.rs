@@ -15,6 +15,7 @@ use pdfium_render::prelude::*;
 use pyo3::prelude::*;
 use pyo3::types::PyBytes;
 use riemann_ocr_worker::OcrEngine;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Mutex;
 
 /// A thread-safe wrapper for the `Pdfium` library instance.
@@ -86,6 +87,502 @@ fn generate_bitmap<'a>(page: &'a PdfPage<'a>, scale: f32) -> PyResult<PdfBitmap<
         .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))
 }
 
+/// Generates a bitmap for a specific PDF page using a themed color scheme.
+///
+/// Unlike `generate_bitmap`, which always renders with the page's native
+/// colors, this passes `config`'s optional path/text fill and stroke colors
+/// through to PDFium's color-scheme-aware renderer, so only text and vector
+/// objects are remapped while embedded images keep their original colors.
+///
+/// # Arguments
+/// * `page` - Reference to the `PdfPage` to render.
+/// * `config` - The `RenderConfig` describing scale, color scheme, and quality flags.
+///
+/// # Returns
+/// A `PyResult` containing the generated `PdfBitmap`, or an error if rendering fails.
+fn generate_themed_bitmap<'a>(
+    page: &'a PdfPage<'a>,
+    config: &RenderConfig,
+) -> PyResult<PdfBitmap<'a>> {
+    let width = (page.width().value * config.scale) as i32;
+    let height = (page.height().value * config.scale) as i32;
+
+    let mut render_config = PdfRenderConfig::new()
+        .set_target_width(width)
+        .set_target_height(height)
+        .rotate_if_landscape(PdfPageRenderRotation::None, true)
+        .render_annotations(config.render_annotations)
+        .set_grayscale_rendering(config.grayscale)
+        .use_text_antialiasing(config.antialias)
+        .use_path_antialiasing(config.antialias);
+
+    if config.path_fill.is_some()
+        || config.path_stroke.is_some()
+        || config.text_fill.is_some()
+        || config.text_stroke.is_some()
+    {
+        let mut scheme = PdfColorScheme::new();
+
+        if let Some((r, g, b, a)) = config.path_fill {
+            scheme = scheme.set_path_fill_color(PdfColor::new(r, g, b, a));
+        }
+        if let Some((r, g, b, a)) = config.path_stroke {
+            scheme = scheme.set_path_stroke_color(PdfColor::new(r, g, b, a));
+        }
+        if let Some((r, g, b, a)) = config.text_fill {
+            scheme = scheme.set_text_fill_color(PdfColor::new(r, g, b, a));
+        }
+        if let Some((r, g, b, a)) = config.text_stroke {
+            scheme = scheme.set_text_stroke_color(PdfColor::new(r, g, b, a));
+        }
+
+        render_config = render_config.set_color_scheme(scheme, config.force);
+    }
+
+    page.render_with_config(&render_config)
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))
+}
+
+/// An axis-aligned bounding box in page points, used for text runs, lines,
+/// blocks, and redaction rectangles.
+///
+/// `top`/`bottom` are normalized on construction so that `top >= bottom`,
+/// matching PDF's bottom-left-origin, y-up coordinate space. This lets the
+/// grouping helpers below compare boxes without re-deriving min/max each time.
+struct Bbox {
+    left: f32,
+    top: f32,
+    right: f32,
+    bottom: f32,
+}
+
+impl Bbox {
+    fn new(left: f32, top: f32, right: f32, bottom: f32) -> Self {
+        Bbox {
+            left: left.min(right),
+            right: left.max(right),
+            top: top.max(bottom),
+            bottom: top.min(bottom),
+        }
+    }
+
+    fn tuple(&self) -> (f32, f32, f32, f32) {
+        (self.left, self.top, self.right, self.bottom)
+    }
+
+    fn width(&self) -> f32 {
+        self.right - self.left
+    }
+
+    fn height(&self) -> f32 {
+        self.top - self.bottom
+    }
+
+    fn overlaps_vertically(&self, other: &Bbox) -> bool {
+        self.bottom < other.top && other.bottom < self.top
+    }
+
+    fn overlaps_horizontally(&self, other: &Bbox) -> bool {
+        self.left < other.right && other.left < self.right
+    }
+
+    fn union(&mut self, other: &Bbox) {
+        self.left = self.left.min(other.left);
+        self.right = self.right.max(other.right);
+        self.top = self.top.max(other.top);
+        self.bottom = self.bottom.min(other.bottom);
+    }
+}
+
+/// A single raw character/word run, as returned by `PdfPageText::segments`.
+struct TextRun {
+    text: String,
+    bbox: Bbox,
+}
+
+impl TextRun {
+    fn avg_char_width(&self) -> f32 {
+        let chars = self.text.chars().count().max(1) as f32;
+        self.bbox.width() / chars
+    }
+}
+
+/// A line produced by `group_runs_into_lines`: its accumulated bounding box
+/// and text.
+struct Line {
+    bbox: Bbox,
+    text: String,
+}
+
+/// Clusters runs into vertical bands (by transitive vertical overlap) and
+/// sorts each band left-to-right, then orders the bands top-to-bottom.
+///
+/// PDFium's `segments()` iterator does not guarantee that runs on the same
+/// line arrive in x-order (this is common with kerned or overlapping runs),
+/// so `group_runs_into_lines` cannot simply trust stream order — it must see
+/// runs pre-sorted within each line, as the layout-analysis spec requires.
+fn sort_runs_into_reading_order(runs: Vec<TextRun>) -> Vec<TextRun> {
+    let n = runs.len();
+    let mut parent: Vec<usize> = (0..n).collect();
+
+    fn find(parent: &mut [usize], x: usize) -> usize {
+        if parent[x] != x {
+            parent[x] = find(parent, parent[x]);
+        }
+        parent[x]
+    }
+
+    for i in 0..n {
+        for j in (i + 1)..n {
+            if runs[i].bbox.overlaps_vertically(&runs[j].bbox) {
+                let (ri, rj) = (find(&mut parent, i), find(&mut parent, j));
+                if ri != rj {
+                    parent[ri] = rj;
+                }
+            }
+        }
+    }
+
+    let mut bands: std::collections::HashMap<usize, Vec<usize>> = std::collections::HashMap::new();
+    for i in 0..n {
+        let root = find(&mut parent, i);
+        bands.entry(root).or_default().push(i);
+    }
+
+    let mut bands: Vec<Vec<usize>> = bands.into_values().collect();
+    for band in &mut bands {
+        band.sort_by(|&a, &b| {
+            runs[a]
+                .bbox
+                .left
+                .partial_cmp(&runs[b].bbox.left)
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+    }
+
+    bands.sort_by(|a, b| {
+        let top_of = |band: &[usize]| -> f32 {
+            band.iter()
+                .map(|&i| runs[i].bbox.top)
+                .fold(f32::MIN, f32::max)
+        };
+        top_of(b)
+            .partial_cmp(&top_of(a))
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+
+    let mut slots: Vec<Option<TextRun>> = runs.into_iter().map(Some).collect();
+    bands
+        .into_iter()
+        .flatten()
+        .filter_map(|index| slots[index].take())
+        .collect()
+}
+
+/// Groups text runs — already sorted into reading order by
+/// `sort_runs_into_reading_order` — into lines, per the
+/// `char_margin`/`word_margin` rule described on
+/// `RiemannDocument::get_page_layout`.
+fn group_runs_into_lines(runs: Vec<TextRun>, char_margin: f32, word_margin: f32) -> Vec<Line> {
+    let mut lines: Vec<Line> = Vec::new();
+    let mut current: Option<(TextRun, Line)> = None;
+
+    for run in runs {
+        if let Some((last_run, line)) = current.as_mut() {
+            let gap = run.bbox.left - last_run.bbox.right;
+            let threshold = char_margin * last_run.avg_char_width().max(run.avg_char_width());
+
+            if last_run.bbox.overlaps_vertically(&run.bbox) && gap <= threshold {
+                if gap > word_margin * last_run.avg_char_width() {
+                    line.text.push(' ');
+                }
+                line.text.push_str(&run.text);
+                line.bbox.union(&run.bbox);
+                *last_run = run;
+                continue;
+            }
+
+            let (_, finished) = current.take().unwrap();
+            lines.push(finished);
+        }
+
+        let line = Line {
+            bbox: Bbox::new(run.bbox.left, run.bbox.top, run.bbox.right, run.bbox.bottom),
+            text: run.text.clone(),
+        };
+        current = Some((run, line));
+    }
+
+    if let Some((_, line)) = current {
+        lines.push(line);
+    }
+
+    lines
+}
+
+/// Groups lines (assumed to already be in natural reading-stream order) into
+/// `LayoutBlock`s, per the `line_margin` rule described on
+/// `RiemannDocument::get_page_layout`.
+fn group_lines_into_blocks(lines: Vec<Line>, line_margin: f32) -> Vec<LayoutBlock> {
+    let mut blocks: Vec<(Bbox, Vec<LayoutLine>)> = Vec::new();
+
+    for line in lines {
+        let joins = blocks.iter().rposition(|(block_bbox, _)| {
+            // Runs are processed in top-to-bottom reading order, so the next
+            // line's top is expected to sit below the block's bottom; a
+            // negative gap just means the boxes already touch or overlap.
+            let gap = block_bbox.bottom - line.bbox.top;
+            let threshold = line_margin * line.bbox.height().max(block_bbox.height());
+            block_bbox.overlaps_horizontally(&line.bbox) && gap <= threshold
+        });
+
+        if let Some(index) = joins {
+            let (block_bbox, block_lines) = &mut blocks[index];
+            block_bbox.union(&line.bbox);
+            block_lines.push((line.bbox.tuple(), line.text));
+        } else {
+            blocks.push((
+                Bbox::new(
+                    line.bbox.left,
+                    line.bbox.top,
+                    line.bbox.right,
+                    line.bbox.bottom,
+                ),
+                vec![(line.bbox.tuple(), line.text)],
+            ));
+        }
+    }
+
+    let mut result: Vec<LayoutBlock> = blocks
+        .into_iter()
+        .map(|(bbox, lines)| (bbox.tuple(), lines))
+        .collect();
+
+    result.sort_by(|a, b| {
+        b.0 .1
+            .partial_cmp(&a.0 .1)
+            .unwrap_or(std::cmp::Ordering::Equal)
+            .then(
+                a.0 .0
+                    .partial_cmp(&b.0 .0)
+                    .unwrap_or(std::cmp::Ordering::Equal),
+            )
+    });
+
+    result
+}
+
+/// Whether two page rectangles overlap, independent of which of PDFium's
+/// `top`/`bottom` is numerically larger.
+fn rects_intersect(a: &PdfRect, b: &PdfRect) -> bool {
+    let a_box = Bbox::new(
+        a.left().value,
+        a.top().value,
+        a.right().value,
+        a.bottom().value,
+    );
+    let b_box = Bbox::new(
+        b.left().value,
+        b.top().value,
+        b.right().value,
+        b.bottom().value,
+    );
+    a_box.overlaps_horizontally(&b_box) && a_box.overlaps_vertically(&b_box)
+}
+
+/// Whether `inner` lies entirely within `outer`.
+fn rect_contains(outer: &PdfRect, inner: &PdfRect) -> bool {
+    let outer_box = Bbox::new(
+        outer.left().value,
+        outer.top().value,
+        outer.right().value,
+        outer.bottom().value,
+    );
+    let inner_box = Bbox::new(
+        inner.left().value,
+        inner.top().value,
+        inner.right().value,
+        inner.bottom().value,
+    );
+    outer_box.left <= inner_box.left
+        && outer_box.right >= inner_box.right
+        && outer_box.bottom <= inner_box.bottom
+        && outer_box.top >= inner_box.top
+}
+
+/// Permanently overwrites the pixels of `object` (an image object) that fall
+/// under `redaction_rect` with `fill_color`, leaving the rest of the image
+/// intact.
+///
+/// Maps `redaction_rect` (page points) into the image's own local pixel
+/// space using its placement bounds, then paints the overlapping pixel
+/// region directly into the image's bitmap so the covered content cannot be
+/// recovered — unlike deleting the whole object, which would also discard
+/// the parts of the image outside the redaction rect.
+fn redact_image_pixels(
+    object: &mut PdfPageObject,
+    redaction_rect: &PdfRect,
+    fill_color: (u8, u8, u8),
+) -> PyResult<()> {
+    let bounds = object
+        .bounds()
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>((e.to_string(),)))?;
+
+    let image = object.as_image_object_mut().ok_or_else(|| {
+        PyErr::new::<pyo3::exceptions::PyRuntimeError, _>("Object is not an image object")
+    })?;
+
+    let bitmap = image
+        .get_raw_bitmap()
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>((e.to_string(),)))?;
+
+    let width = bitmap.width() as u32;
+    let height = bitmap.height() as u32;
+    let mut buffer = bitmap.as_raw_bytes().to_vec();
+
+    let obj_width = (bounds.right().value - bounds.left().value).max(f32::MIN_POSITIVE);
+    let obj_height = (bounds.top().value - bounds.bottom().value).max(f32::MIN_POSITIVE);
+
+    let to_px_x = |page_x: f32| -> u32 {
+        (((page_x - bounds.left().value) / obj_width * width as f32).clamp(0.0, width as f32))
+            as u32
+    };
+    // Image rows run top-to-bottom while PDF page coordinates run bottom-to-top.
+    let to_px_y = |page_y: f32| -> u32 {
+        (((bounds.top().value - page_y) / obj_height * height as f32).clamp(0.0, height as f32))
+            as u32
+    };
+
+    let overlap_left = redaction_rect.left().value.max(bounds.left().value);
+    let overlap_right = redaction_rect.right().value.min(bounds.right().value);
+    let overlap_bottom = redaction_rect.bottom().value.max(bounds.bottom().value);
+    let overlap_top = redaction_rect.top().value.min(bounds.top().value);
+
+    let px_left = to_px_x(overlap_left);
+    let px_right = to_px_x(overlap_right);
+    let px_top = to_px_y(overlap_top);
+    let px_bottom = to_px_y(overlap_bottom);
+
+    let stride = width as usize * 4;
+    for y in px_top..px_bottom {
+        for x in px_left..px_right {
+            let offset = y as usize * stride + x as usize * 4;
+            if offset + 3 < buffer.len() {
+                // Raw pixel data is BGRA, matching `render_page`'s buffer layout.
+                buffer[offset] = fill_color.2;
+                buffer[offset + 1] = fill_color.1;
+                buffer[offset + 2] = fill_color.0;
+                buffer[offset + 3] = 255;
+            }
+        }
+    }
+
+    let redacted_bitmap = PdfBitmap::from_raw_bytes(width as i32, height as i32, buffer)
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>((e.to_string(),)))?;
+
+    image
+        .set_bitmap(&redacted_bitmap)
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>((e.to_string(),)))
+}
+
+/// Walks a path object's raw PDFium segments and converts them into
+/// `PathSegment` tuples.
+///
+/// PDFium represents a single cubic Bezier curve as three consecutive
+/// `BezierTo` segments (control point 1, control point 2, end point), so
+/// those are grouped back into one `bezier` entry with all three points.
+fn path_segments(path: &PdfPagePathObject) -> Vec<PathSegment> {
+    let raw: Vec<_> = path.segments().iter().collect();
+    let mut segments = Vec::new();
+    let mut i = 0;
+
+    while i < raw.len() {
+        let segment = &raw[i];
+        match segment.segment_type() {
+            PdfPathSegmentType::MoveTo => {
+                segments.push(("move".to_string(), vec![(segment.x(), segment.y())]));
+                if segment.is_closed() {
+                    segments.push(("close".to_string(), Vec::new()));
+                }
+                i += 1;
+            }
+            PdfPathSegmentType::LineTo => {
+                segments.push(("line".to_string(), vec![(segment.x(), segment.y())]));
+                if segment.is_closed() {
+                    segments.push(("close".to_string(), Vec::new()));
+                }
+                i += 1;
+            }
+            PdfPathSegmentType::BezierTo => {
+                let control_1 = (segment.x(), segment.y());
+                let control_2 = raw.get(i + 1).map(|s| (s.x(), s.y())).unwrap_or(control_1);
+                let end = raw.get(i + 2).map(|s| (s.x(), s.y())).unwrap_or(control_2);
+                let closed = raw.get(i + 2).map(|s| s.is_closed()).unwrap_or(false);
+
+                segments.push(("bezier".to_string(), vec![control_1, control_2, end]));
+                if closed {
+                    segments.push(("close".to_string(), Vec::new()));
+                }
+                i += 3;
+            }
+            PdfPathSegmentType::Unknown => {
+                i += 1;
+            }
+        }
+    }
+
+    segments
+}
+
+/// Collapses axis-aligned `move → line → line → line → close` subpaths (as
+/// emitted for the PDF content stream `re` rectangle operator) into a single
+/// `rect` segment carrying its two opposite corners.
+fn collapse_rect_subpaths(segments: Vec<PathSegment>) -> Vec<PathSegment> {
+    let mut out = Vec::with_capacity(segments.len());
+    let mut i = 0;
+
+    while i < segments.len() {
+        if let Some(rect) = try_match_rect(&segments, i) {
+            out.push(rect);
+            i += 5;
+            continue;
+        }
+        out.push(segments[i].clone());
+        i += 1;
+    }
+
+    out
+}
+
+/// Attempts to match a rectangular subpath starting at `segments[start]`.
+fn try_match_rect(segments: &[PathSegment], start: usize) -> Option<PathSegment> {
+    let window = segments.get(start..start + 5)?;
+    let [mv, l1, l2, l3, close] = window else {
+        return None;
+    };
+
+    if mv.0 != "move" || l1.0 != "line" || l2.0 != "line" || l3.0 != "line" || close.0 != "close" {
+        return None;
+    }
+
+    let p0 = *mv.1.first()?;
+    let p1 = *l1.1.first()?;
+    let p2 = *l2.1.first()?;
+    let p3 = *l3.1.first()?;
+
+    let is_axis_aligned = (p0.0 == p1.0 && p1.1 == p2.1 && p2.0 == p3.0 && p3.1 == p0.1)
+        || (p0.1 == p1.1 && p1.0 == p2.0 && p2.1 == p3.1 && p3.0 == p0.0);
+
+    if !is_axis_aligned {
+        return None;
+    }
+
+    let (min_x, max_x) = (p0.0.min(p2.0), p0.0.max(p2.0));
+    let (min_y, max_y) = (p0.1.min(p2.1), p0.1.max(p2.1));
+
+    Some(("rect".to_string(), vec![(min_x, min_y), (max_x, max_y)]))
+}
+
 /// Type definition for form widget data.
 /// Tuple structure: `(index, bounds_tuple, field_type, value, is_checked)`.
 type FormWidget = (usize, (f32, f32, f32, f32), String, String, bool);
@@ -94,6 +591,38 @@ type FormWidget = (usize, (f32, f32, f32, f32), String, String, bool);
 /// Tuple structure: `(text_content, bounds_tuple)`.
 type TextSegment = (String, (f32, f32, f32, f32));
 
+/// Type definition for word-level OCR segment data.
+/// Tuple structure: `(text_content, bounds_tuple, confidence)`.
+type OcrSegment = (String, (f32, f32, f32, f32), f32);
+
+/// A single segment of a vector path.
+/// Tuple structure: `(kind, points)` where `kind` is one of `"move"`, `"line"`,
+/// `"bezier"`, `"rect"`, or `"close"`. `points` holds the segment's
+/// coordinates: one point for `move`/`line`, two opposite corners for `rect`,
+/// three points (control 1, control 2, end) for `bezier`, and none for `close`.
+type PathSegment = (String, Vec<(f32, f32)>);
+
+/// A line of text within a `get_page_layout` block.
+/// Tuple structure: `(bounds_tuple, line_text)`.
+type LayoutLine = ((f32, f32, f32, f32), String);
+
+/// A block of text produced by `get_page_layout`, in reading order.
+/// Tuple structure: `(bounds_tuple, lines)`.
+type LayoutBlock = ((f32, f32, f32, f32), Vec<LayoutLine>);
+
+/// A vector drawing extracted from a page, mirroring pdfminer's `paint_path`.
+/// Tuple structure: `(segments, stroke_color, fill_color, fill_mode, line_width, bounds_tuple)`.
+/// `stroke_color`/`fill_color` are `(r, g, b, a)` or `None` when the path has
+/// no stroke/fill. `fill_mode` is `"nonzero"`, `"evenodd"`, or `"none"`.
+type Drawing = (
+    Vec<PathSegment>,
+    Option<(u8, u8, u8, u8)>,
+    Option<(u8, u8, u8, u8)>,
+    String,
+    f32,
+    (f32, f32, f32, f32),
+);
+
 /// Encapsulates the output of a page render operation.
 ///
 /// This struct is exposed to Python to provide the raw pixel data along with
@@ -111,6 +640,87 @@ struct RenderResult {
     data: Py<PyBytes>,
 }
 
+/// Configuration for a themed page render, modeled on pypdfium2's
+/// `PdfColorScheme`.
+///
+/// `path_fill`, `path_stroke`, `text_fill`, and `text_stroke` are optional
+/// RGBA colors (`(r, g, b, a)`); a page render remaps only the vector/text
+/// objects for which a color is set, leaving images untouched. When none of
+/// the four colors are set, rendering falls back to the page's native colors.
+#[pyclass]
+#[derive(Clone)]
+struct RenderConfig {
+    /// Zoom level/scaling factor.
+    #[pyo3(get, set)]
+    scale: f32,
+    /// Replacement fill color for vector path (fill) objects.
+    #[pyo3(get, set)]
+    path_fill: Option<(u8, u8, u8, u8)>,
+    /// Replacement stroke color for vector path (stroke) objects.
+    #[pyo3(get, set)]
+    path_stroke: Option<(u8, u8, u8, u8)>,
+    /// Replacement fill color for text objects.
+    #[pyo3(get, set)]
+    text_fill: Option<(u8, u8, u8, u8)>,
+    /// Replacement stroke color for text objects.
+    #[pyo3(get, set)]
+    text_stroke: Option<(u8, u8, u8, u8)>,
+    /// When `true`, applies the configured colors even to objects that already
+    /// specify their own color; when `false`, only colorless (e.g. pattern or
+    /// shading-less) objects are remapped.
+    #[pyo3(get, set)]
+    force: bool,
+    /// Whether to render page annotations.
+    #[pyo3(get, set)]
+    render_annotations: bool,
+    /// Whether to render in grayscale.
+    #[pyo3(get, set)]
+    grayscale: bool,
+    /// Whether to apply antialiasing to text and vector graphics.
+    #[pyo3(get, set)]
+    antialias: bool,
+}
+
+#[pymethods]
+impl RenderConfig {
+    #[new]
+    #[pyo3(signature = (
+        scale = 1.0,
+        path_fill = None,
+        path_stroke = None,
+        text_fill = None,
+        text_stroke = None,
+        force = false,
+        render_annotations = true,
+        grayscale = false,
+        antialias = true
+    ))]
+    #[allow(clippy::too_many_arguments)]
+    fn new(
+        scale: f32,
+        path_fill: Option<(u8, u8, u8, u8)>,
+        path_stroke: Option<(u8, u8, u8, u8)>,
+        text_fill: Option<(u8, u8, u8, u8)>,
+        text_stroke: Option<(u8, u8, u8, u8)>,
+        force: bool,
+        render_annotations: bool,
+        grayscale: bool,
+        antialias: bool,
+    ) -> Self {
+        RenderConfig {
+            scale,
+            path_fill,
+            path_stroke,
+            text_fill,
+            text_stroke,
+            force,
+            render_annotations,
+            grayscale,
+            antialias,
+        }
+    }
+}
+
 /// A Python-compatible wrapper around a loaded PDF document.
 ///
 /// This struct manages the lifetime and thread-safe access to the underlying
@@ -120,12 +730,30 @@ struct RenderResult {
 struct RiemannDocument {
     inner: Mutex<DocumentWrapper>,
     /// The total number of pages in the document.
-    #[pyo3(get)]
-    page_count: usize,
+    ///
+    /// Cached outside of `inner` so the value can be read without locking the
+    /// document mutex; kept in sync by any method that inserts or removes pages.
+    page_count: AtomicUsize,
+    /// Redactions marked via `mark_redaction` but not yet applied.
+    pending_redactions: Mutex<Vec<Redaction>>,
+}
+
+/// A rectangle marked for permanent removal by `apply_redactions`.
+struct Redaction {
+    page_index: u16,
+    rect: (f32, f32, f32, f32),
+    fill_color: (u8, u8, u8),
+    overlay_text: Option<String>,
 }
 
 #[pymethods]
 impl RiemannDocument {
+    /// The total number of pages in the document.
+    #[getter]
+    fn page_count(&self) -> usize {
+        self.page_count.load(Ordering::SeqCst)
+    }
+
     /// Renders a specific page into a byte buffer.
     ///
     /// This method handles scaling and optional dark mode inversion.
@@ -174,6 +802,44 @@ impl RiemannDocument {
         })
     }
 
+    /// Renders a specific page into a byte buffer using a themed color scheme.
+    ///
+    /// Replaces naive "dark mode" channel inversion with PDFium's
+    /// color-scheme-aware renderer: only text and vector objects are remapped
+    /// to the colors in `config`, so images and photographs keep their
+    /// original colors.
+    ///
+    /// # Arguments
+    /// * `py` - The Python GIL token.
+    /// * `page_index` - Zero-based index of the page to render.
+    /// * `config` - The `RenderConfig` describing scale, color scheme, and quality flags.
+    ///
+    /// # Returns
+    /// A `RenderResult` object containing the image data.
+    fn render_page_themed(
+        &self,
+        py: Python,
+        page_index: u16,
+        config: &RenderConfig,
+    ) -> PyResult<RenderResult> {
+        let doc_guard = self.inner.lock().unwrap();
+
+        let page = doc_guard
+            .0
+            .pages()
+            .get(page_index)
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+
+        let bitmap = generate_themed_bitmap(&page, config)?;
+        let data = PyBytes::new_bound(py, bitmap.as_raw_bytes());
+
+        Ok(RenderResult {
+            width: bitmap.width() as u32,
+            height: bitmap.height() as u32,
+            data: data.into(),
+        })
+    }
+
     /// Extracts all plain text from a specific page.
     ///
     /// # Arguments
@@ -243,6 +909,148 @@ impl RiemannDocument {
         Ok(text)
     }
 
+    /// Performs OCR on a page and returns word-level segments with bounding boxes.
+    ///
+    /// Unlike `ocr_page`, which collapses the recognized text into a flat string,
+    /// this renders the page, invokes Tesseract with the given language and
+    /// page-segmentation mode, and keeps each word's position and confidence so
+    /// it can be positioned and highlighted just like `get_text_segments`. Boxes
+    /// are converted out of the rendered bitmap's pixel space (scaled, y-down)
+    /// back into PDF page points (y-up), matching `get_text_segments`'s contract.
+    ///
+    /// # Arguments
+    /// * `page_index` - Zero-based index of the page.
+    /// * `scale` - Scale factor for the image. Higher scales (2.0+) improve accuracy.
+    /// * `lang` - Tesseract language string, e.g. `"eng"` or `"eng+deu"`.
+    /// * `psm` - Tesseract page-segmentation mode.
+    ///
+    /// # Returns
+    /// A list of `OcrSegment` tuples with boxes in PDF page-point space.
+    fn ocr_page_segments(
+        &self,
+        page_index: u16,
+        scale: f32,
+        lang: String,
+        psm: u32,
+    ) -> PyResult<Vec<OcrSegment>> {
+        let doc_guard = self.inner.lock().unwrap();
+
+        let page = doc_guard
+            .0
+            .pages()
+            .get(page_index)
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+
+        let bitmap = generate_bitmap(&page, scale)?;
+        let mut buffer = bitmap.as_raw_bytes().to_vec();
+
+        buffer.chunks_exact_mut(4).for_each(|pixel| {
+            let blue = pixel[0];
+            let red = pixel[2];
+            pixel[0] = red;
+            pixel[2] = blue;
+        });
+
+        let engine = OcrEngine::new();
+        let words = engine
+            .recognize_segments(
+                bitmap.width() as u32,
+                bitmap.height() as u32,
+                &buffer,
+                &lang,
+                psm,
+            )
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?;
+
+        // Tesseract's boxes are in the rendered bitmap's pixel space: origin
+        // top-left, y growing downward, scaled up by `scale`. `get_text_segments`
+        // returns PDF page points: origin bottom-left, y growing upward. Map
+        // back into that space so OCR segments can be positioned/highlighted
+        // the same way, dividing out the render scale and flipping y against
+        // the page's height.
+        let page_height = page.height().value;
+        Ok(words
+            .into_iter()
+            .map(|(text, (left, top, right, bottom), conf)| {
+                let left = left as f32 / scale;
+                let right = right as f32 / scale;
+                let top = page_height - (top as f32 / scale);
+                let bottom = page_height - (bottom as f32 / scale);
+                (text, (left, top, right, bottom), conf)
+            })
+            .collect())
+    }
+
+    /// Generates a searchable text layer for a scanned page and merges it in place.
+    ///
+    /// Builds on `ocr_page`: renders the page to a bitmap, feeds it to Tesseract
+    /// with the `pdf` output configurator (which produces a single-page PDF with
+    /// an invisible OCR text layer precisely aligned to the image), then loads
+    /// that PDF back in and replaces the original page with the OCR'd one. After
+    /// this call, `get_page_text` and `search_page` work on what was previously
+    /// an image-only page.
+    ///
+    /// # Arguments
+    /// * `page_index` - Zero-based index of the page to make searchable.
+    /// * `scale` - Scale factor used when rendering the page for OCR.
+    /// * `lang` - Tesseract language string, e.g. `"eng"` or `"eng+deu"`.
+    fn ocr_page_searchable(&self, page_index: u16, scale: f32, lang: String) -> PyResult<()> {
+        let mut doc_guard = self.inner.lock().unwrap();
+
+        let pdf_bytes = {
+            let page = doc_guard
+                .0
+                .pages()
+                .get(page_index)
+                .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+
+            let bitmap = generate_bitmap(&page, scale)?;
+            let mut buffer = bitmap.as_raw_bytes().to_vec();
+
+            buffer.chunks_exact_mut(4).for_each(|pixel| {
+                let blue = pixel[0];
+                let red = pixel[2];
+                pixel[0] = red;
+                pixel[2] = blue;
+            });
+
+            let engine = OcrEngine::new();
+            engine
+                .recognize_pdf(
+                    bitmap.width() as u32,
+                    bitmap.height() as u32,
+                    &buffer,
+                    &lang,
+                )
+                .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?
+        };
+
+        let ocr_doc = get_pdfium()
+            .load_pdf_from_byte_vec(pdf_bytes, None)
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?;
+
+        // `copy_page_from_document` maps to `FPDF_ImportPages`, which *inserts*
+        // the OCR'd page at `page_index` rather than replacing it — the
+        // original image-only page shifts to `page_index + 1`. Delete it so
+        // the OCR'd page actually takes its place.
+        doc_guard
+            .0
+            .pages_mut()
+            .copy_page_from_document(&ocr_doc, 0, page_index)
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?;
+
+        doc_guard
+            .0
+            .pages_mut()
+            .delete_page_at_index(page_index + 1)
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?;
+
+        self.page_count
+            .store(doc_guard.0.pages().len(), Ordering::SeqCst);
+
+        Ok(())
+    }
+
     /// Searches the page for a specific text term.
     ///
     /// # Arguments
@@ -336,6 +1144,162 @@ impl RiemannDocument {
         Ok(segments)
     }
 
+    /// Performs pdfminer-style layout analysis over the page's raw text runs.
+    ///
+    /// `get_text_segments` dumps PDFium's raw character/word runs with no
+    /// structure; this first sorts runs within each vertical band left-to-right
+    /// (PDFium's stream order is not guaranteed to be monotonic in x), then
+    /// groups them into lines and lines into blocks. Two runs join a line if
+    /// they overlap vertically and their horizontal gap is below
+    /// `char_margin * avg_char_width` (a space is inserted when
+    /// the gap exceeds `word_margin * avg_char_width`). Two lines join a block
+    /// if they are horizontally overlapping/aligned and their vertical gap is
+    /// below `line_margin * line_height`. Blocks are returned ordered
+    /// top-to-bottom, left-to-right, so the UI can do paragraph selection and
+    /// reading-order extraction rather than scattered word boxes.
+    ///
+    /// # Arguments
+    /// * `page_index` - Zero-based index of the page.
+    /// * `char_margin` - Horizontal-gap threshold (as a multiple of average character width) for joining runs into a line.
+    /// * `line_margin` - Vertical-gap threshold (as a multiple of line height) for joining lines into a block.
+    /// * `word_margin` - Horizontal-gap threshold (as a multiple of average character width) for inserting a space between runs.
+    ///
+    /// # Returns
+    /// A list of `LayoutBlock` tuples.
+    #[pyo3(signature = (page_index, char_margin = 2.0, line_margin = 0.5, word_margin = 0.1))]
+    fn get_page_layout(
+        &self,
+        page_index: u16,
+        char_margin: f32,
+        line_margin: f32,
+        word_margin: f32,
+    ) -> PyResult<Vec<LayoutBlock>> {
+        let doc_guard = self.inner.lock().unwrap();
+        let pages = doc_guard.0.pages();
+
+        if (page_index as usize) >= (pages.len() as usize) {
+            return Ok(Vec::new());
+        }
+
+        let page = pages
+            .get(page_index)
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>((e.to_string(),)))?;
+
+        let text_accessor = page.text().map_err(|e| {
+            PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("Text Access Error: {}", e))
+        })?;
+
+        let runs: Vec<TextRun> = text_accessor
+            .segments()
+            .iter()
+            .filter_map(|segment| {
+                let text = segment.text();
+                if text.trim().is_empty() {
+                    return None;
+                }
+                let bounds = segment.bounds();
+                Some(TextRun {
+                    text,
+                    bbox: Bbox::new(
+                        bounds.left().value,
+                        bounds.top().value,
+                        bounds.right().value,
+                        bounds.bottom().value,
+                    ),
+                })
+            })
+            .collect();
+
+        let runs = sort_runs_into_reading_order(runs);
+        let lines = group_runs_into_lines(runs, char_margin, word_margin);
+        let blocks = group_lines_into_blocks(lines, line_margin);
+
+        Ok(blocks)
+    }
+
+    /// Extracts the page's vector graphics (paths), mirroring what pdfminer's
+    /// `paint_path` exposes.
+    ///
+    /// Walks the page objects and, for each path object, emits its subpaths as
+    /// a sequence of `move`/`line`/`bezier`/`rect`/`close` segments together
+    /// with its stroke color, fill color, fill mode, line width, and bounding
+    /// box, so consumers can reconstruct tables, underlines, form rules, and
+    /// figures that are invisible to pure text extraction.
+    ///
+    /// # Arguments
+    /// * `page_index` - Zero-based index of the page.
+    ///
+    /// # Returns
+    /// A list of `Drawing` tuples.
+    fn get_page_drawings(&self, page_index: u16) -> PyResult<Vec<Drawing>> {
+        let doc_guard = self.inner.lock().unwrap();
+        let pages = doc_guard.0.pages();
+
+        if (page_index as usize) >= (pages.len() as usize) {
+            return Ok(Vec::new());
+        }
+
+        let page = pages
+            .get(page_index)
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>((e.to_string(),)))?;
+
+        let mut drawings = Vec::new();
+
+        for object in page.objects().iter() {
+            let Some(path) = object.as_path_object() else {
+                continue;
+            };
+
+            let segments = collapse_rect_subpaths(path_segments(path));
+
+            let stroke_color = path
+                .stroke_color()
+                .ok()
+                .filter(|_| path.is_stroked().unwrap_or(false))
+                .map(|c| (c.red(), c.green(), c.blue(), c.alpha()));
+
+            let fill_color = path
+                .fill_color()
+                .ok()
+                .filter(|_| {
+                    matches!(
+                        path.fill_mode(),
+                        Ok(PdfPathFillMode::Winding) | Ok(PdfPathFillMode::EvenOdd)
+                    )
+                })
+                .map(|c| (c.red(), c.green(), c.blue(), c.alpha()));
+
+            let fill_mode = match path.fill_mode() {
+                Ok(PdfPathFillMode::Winding) => "nonzero",
+                Ok(PdfPathFillMode::EvenOdd) => "evenodd",
+                _ => "none",
+            }
+            .to_string();
+
+            let line_width = path.stroke_width().map(|w| w.value).unwrap_or(0.0);
+
+            let bounds = object
+                .bounds()
+                .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>((e.to_string(),)))?;
+
+            drawings.push((
+                segments,
+                stroke_color,
+                fill_color,
+                fill_mode,
+                line_width,
+                (
+                    bounds.left().value,
+                    bounds.top().value,
+                    bounds.right().value,
+                    bounds.bottom().value,
+                ),
+            ));
+        }
+
+        Ok(drawings)
+    }
+
     /// Adds a markup annotation (highlight, underline, or strikeout) to the page.
     ///
     /// This function calculates the union rectangle of all passed `rects` to set
@@ -466,6 +1430,182 @@ impl RiemannDocument {
         Ok(())
     }
 
+    /// Marks a rectangle on a page for permanent redaction.
+    ///
+    /// Does not modify the document; call `apply_redactions` to actually
+    /// remove the content underneath the marked rectangles.
+    ///
+    /// # Arguments
+    /// * `page_index` - Zero-based index of the page.
+    /// * `rect` - Bounding box `(left, top, right, bottom)` to redact.
+    /// * `fill_color` - RGB color painted over the redacted area.
+    /// * `overlay_text` - Optional replacement text drawn over the fill.
+    #[pyo3(signature = (page_index, rect, fill_color, overlay_text=None))]
+    fn mark_redaction(
+        &self,
+        page_index: u16,
+        rect: (f32, f32, f32, f32),
+        fill_color: (u8, u8, u8),
+        overlay_text: Option<String>,
+    ) -> PyResult<()> {
+        self.pending_redactions.lock().unwrap().push(Redaction {
+            page_index,
+            rect,
+            fill_color,
+            overlay_text,
+        });
+        Ok(())
+    }
+
+    /// Permanently removes the content underneath all marked redaction rectangles.
+    ///
+    /// For every pending redaction, this destroys the content underneath the
+    /// redaction rectangle, paints the rectangle with `fill_color`, draws
+    /// `overlay_text` on top if provided, and re-flattens the page. Unlike
+    /// `create_markup_annotation`, this mutates the page's content stream
+    /// directly rather than drawing an annotation on top, so the removed
+    /// content is gone from `get_page_text` and `search_page` and cannot be
+    /// recovered by copy-paste.
+    ///
+    /// Text and vector-path objects are removed outright as soon as their
+    /// bounds intersect the rect at all: PDFium has no API in this crate for
+    /// splitting a text run or path into the sub-glyphs/subpaths that fall
+    /// outside the rect, and a text object typically spans an entire
+    /// line/run, so keeping a partially-overlapping run in place (painting
+    /// over it instead of removing it) would leave the covered word still
+    /// present in the content stream and recoverable via `get_page_text`. The
+    /// only object kind this crate can *safely* shrink in place is an image,
+    /// whose covered pixels can be overwritten without touching the parts of
+    /// the image outside the rect.
+    fn apply_redactions(&self) -> PyResult<()> {
+        let redactions = std::mem::take(&mut *self.pending_redactions.lock().unwrap());
+        let mut doc_guard = self.inner.lock().unwrap();
+
+        for redaction in &redactions {
+            let mut pages = doc_guard.0.pages_mut();
+            let mut page = pages
+                .get(redaction.page_index)
+                .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>((e.to_string(),)))?;
+
+            let (left, top, right, bottom) = redaction.rect;
+            let redaction_rect = PdfRect::new_from_values(left, bottom, right, top);
+
+            // Images keep the pixels outside the rect: only the covered
+            // region is overwritten, in place, below. Every other object
+            // kind (text runs, vector paths) is removed outright the moment
+            // it intersects the rect at all, since this crate has no way to
+            // destroy only the covered portion of a run without deleting the
+            // whole object — see the doc comment above for why a "leave it
+            // in place under the overlay" compromise is not safe here.
+            let fully_removed: Vec<_> = page
+                .objects()
+                .iter()
+                .filter(|object| {
+                    let intersects = object
+                        .bounds()
+                        .map(|bounds| rects_intersect(&bounds, &redaction_rect))
+                        .unwrap_or(false);
+                    intersects && !object.is_image_object()
+                })
+                .collect();
+
+            for object in fully_removed {
+                object.remove_object_from_page().map_err(|e| {
+                    PyErr::new::<pyo3::exceptions::PyRuntimeError, _>((e.to_string(),))
+                })?;
+            }
+
+            let fully_contained_images: Vec<_> = page
+                .objects()
+                .iter()
+                .filter(|object| {
+                    object.is_image_object()
+                        && object
+                            .bounds()
+                            .map(|bounds| rect_contains(&redaction_rect, &bounds))
+                            .unwrap_or(false)
+                })
+                .collect();
+
+            for object in fully_contained_images {
+                object.remove_object_from_page().map_err(|e| {
+                    PyErr::new::<pyo3::exceptions::PyRuntimeError, _>((e.to_string(),))
+                })?;
+            }
+
+            let partially_intersecting_images: Vec<_> = page
+                .objects_mut()
+                .iter_mut()
+                .filter(|object| {
+                    object.is_image_object()
+                        && object
+                            .bounds()
+                            .map(|bounds| {
+                                rects_intersect(&bounds, &redaction_rect)
+                                    && !rect_contains(&redaction_rect, &bounds)
+                            })
+                            .unwrap_or(false)
+                })
+                .collect();
+
+            for object in partially_intersecting_images {
+                redact_image_pixels(object, &redaction_rect, redaction.fill_color)?;
+            }
+
+            let fill_color = PdfColor::new(
+                redaction.fill_color.0,
+                redaction.fill_color.1,
+                redaction.fill_color.2,
+                255,
+            );
+
+            let mut fill_object = PdfPagePathObject::new_rect(
+                &doc_guard.0,
+                redaction_rect,
+                None,
+                None,
+                Some(fill_color),
+            )
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>((e.to_string(),)))?;
+            fill_object
+                .set_fill_color(fill_color)
+                .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>((e.to_string(),)))?;
+
+            page.objects_mut()
+                .add_path_object(fill_object)
+                .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>((e.to_string(),)))?;
+
+            if let Some(text) = &redaction.overlay_text {
+                let font = doc_guard.0.fonts_mut().helvetica();
+                let mut text_object =
+                    PdfPageTextObject::new(&doc_guard.0, text, font, PdfPoints::new(12.0))
+                        .map_err(|e| {
+                            PyErr::new::<pyo3::exceptions::PyRuntimeError, _>((e.to_string(),))
+                        })?;
+                text_object
+                    .translate(PdfPoints::new(left), PdfPoints::new(bottom))
+                    .map_err(|e| {
+                        PyErr::new::<pyo3::exceptions::PyRuntimeError, _>((e.to_string(),))
+                    })?;
+
+                page.objects_mut()
+                    .add_text_object(text_object)
+                    .map_err(|e| {
+                        PyErr::new::<pyo3::exceptions::PyRuntimeError, _>((e.to_string(),))
+                    })?;
+            }
+
+            page.flatten().map_err(|e| {
+                PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+                    "Failed to flatten page: {}",
+                    e
+                ))
+            })?;
+        }
+
+        Ok(())
+    }
+
     /// Extracts interactive form field widgets from the page.
     ///
     /// Identifies text fields, checkboxes, and radio buttons, retrieving their
@@ -529,6 +1669,51 @@ impl RiemannDocument {
         }
         Ok(widgets)
     }
+
+    /// Enumerates the document's optional content groups (layers).
+    ///
+    /// Many PDFs (CAD exports, maps, multilingual docs) organize their content
+    /// into optional content groups (OCGs) that can be shown or hidden
+    /// independently.
+    ///
+    /// # Returns
+    /// A list of `(layer_index, name, is_visible)` tuples.
+    fn get_optional_content_groups(&self) -> PyResult<Vec<(usize, String, bool)>> {
+        let doc_guard = self.inner.lock().unwrap();
+        let groups = doc_guard.0.pages().optional_content_groups();
+
+        let mut result = Vec::new();
+        for (index, group) in groups.iter().enumerate() {
+            result.push((index, group.name().unwrap_or_default(), group.is_visible()));
+        }
+
+        Ok(result)
+    }
+
+    /// Toggles the visibility of an optional content group (layer).
+    ///
+    /// Takes effect on the next call to `render_page` or `render_page_themed`,
+    /// which otherwise always rasterize every layer.
+    ///
+    /// # Arguments
+    /// * `layer_index` - Index of the layer, as returned by `get_optional_content_groups`.
+    /// * `visible` - Whether the layer's content should be rendered.
+    fn set_optional_content_group_visibility(
+        &self,
+        layer_index: usize,
+        visible: bool,
+    ) -> PyResult<()> {
+        let mut doc_guard = self.inner.lock().unwrap();
+        let mut groups = doc_guard.0.pages_mut().optional_content_groups_mut();
+
+        let mut group = groups
+            .get(layer_index)
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>((e.to_string(),)))?;
+
+        group
+            .set_visible(visible)
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>((e.to_string(),)))
+    }
 }
 
 /// The main entry point for the PDF Engine.
@@ -558,8 +1743,9 @@ impl PdfEngine {
             .map_err(|e| PyErr::new::<pyo3::exceptions::PyIOError, _>(e.to_string()))?;
 
         Ok(RiemannDocument {
-            page_count: doc.pages().len() as usize,
+            page_count: AtomicUsize::new(doc.pages().len()),
             inner: Mutex::new(DocumentWrapper(doc)),
+            pending_redactions: Mutex::new(Vec::new()),
         })
     }
 }
@@ -572,5 +1758,6 @@ fn riemann_core(_py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<PdfEngine>()?;
     m.add_class::<RiemannDocument>()?;
     m.add_class::<RenderResult>()?;
+    m.add_class::<RenderConfig>()?;
     Ok(())
 }
@@ -48,41 +48,161 @@ impl OcrEngine {
     /// Errors may occur if `tesseract` is missing from the PATH, if the image encoding
     /// fails, or if the process exits with a non-zero status.
     pub fn recognize_text(&self, width: u32, height: u32, data: &[u8]) -> Result<String> {
-        let buffer: ImageBuffer<Rgba<u8>, _> = ImageBuffer::from_raw(width, height, data)
-            .context("Failed to create image buffer from raw pixel data")?;
-
-        let mut png_data = Vec::new();
-        let encoder = image::codecs::png::PngEncoder::new(&mut png_data);
-        buffer
-            .write_with_encoder(encoder)
-            .context("Failed to encode in-memory PNG for OCR processing")?;
-
-        let mut child = Command::new("tesseract")
-            .arg("stdin")
-            .arg("stdout")
-            .arg("-l")
-            .arg("eng")
-            .stdin(Stdio::piped())
-            .stdout(Stdio::piped())
-            .stderr(Stdio::piped())
-            .spawn()
-            .context("Tesseract process failed to start. Please ensure 'tesseract-ocr' is installed and in your PATH.")?;
-
-        if let Some(mut stdin) = child.stdin.take() {
-            stdin
-                .write_all(&png_data)
-                .context("Failed to pipe PNG data to Tesseract stdin")?;
+        let png_data = encode_png(width, height, data)?;
+        let stdout = run_tesseract(&png_data, &["-l", "eng"])?;
+        Ok(String::from_utf8_lossy(&stdout).to_string())
+    }
+
+    /// Recognizes text from raw pixel data with configurable language and
+    /// page-segmentation mode, returning per-word results with coordinates.
+    ///
+    /// Runs `tesseract stdin stdout -l <lang> --psm <psm> tsv` and parses the
+    /// resulting TSV, keeping only word-level rows (`level == 5`) with
+    /// non-empty text.
+    ///
+    /// # Arguments
+    /// * `width` - Image width in pixels.
+    /// * `height` - Image height in pixels.
+    /// * `data` - Raw slice of RGBA pixel data.
+    /// * `lang` - Tesseract language string, e.g. `"eng"` or `"eng+deu"`.
+    /// * `psm` - Tesseract page-segmentation mode.
+    ///
+    /// # Returns
+    /// A `Result` containing one `OcrSegment` per recognized word, in
+    /// reading order.
+    pub fn recognize_segments(
+        &self,
+        width: u32,
+        height: u32,
+        data: &[u8],
+        lang: &str,
+        psm: u32,
+    ) -> Result<Vec<OcrSegment>> {
+        let png_data = encode_png(width, height, data)?;
+        let psm_arg = psm.to_string();
+        let stdout = run_tesseract(&png_data, &["-l", lang, "--psm", &psm_arg, "tsv"])?;
+        parse_tsv_words(&stdout)
+    }
+
+    /// Generates a searchable PDF from raw pixel data.
+    ///
+    /// Runs `tesseract stdin stdout -l <lang> pdf`, which produces a single-page
+    /// PDF containing the original image with an invisible OCR text layer
+    /// precisely aligned on top of it. The caller is responsible for merging the
+    /// resulting bytes into a document.
+    ///
+    /// # Arguments
+    /// * `width` - Image width in pixels.
+    /// * `height` - Image height in pixels.
+    /// * `data` - Raw slice of RGBA pixel data.
+    /// * `lang` - Tesseract language string, e.g. `"eng"` or `"eng+deu"`.
+    ///
+    /// # Returns
+    /// A `Result` containing the raw bytes of the generated PDF.
+    pub fn recognize_pdf(
+        &self,
+        width: u32,
+        height: u32,
+        data: &[u8],
+        lang: &str,
+    ) -> Result<Vec<u8>> {
+        let png_data = encode_png(width, height, data)?;
+        run_tesseract(&png_data, &["-l", lang, "pdf"])
+    }
+}
+
+/// A recognized word, its bounding box, and Tesseract's confidence score.
+/// Tuple structure: `(text, (left, top, right, bottom), confidence)`.
+pub type OcrSegment = (String, (i32, i32, i32, i32), f32);
+
+/// Wraps raw RGBA pixel data into an in-memory PNG, ready to be piped to
+/// Tesseract's stdin.
+fn encode_png(width: u32, height: u32, data: &[u8]) -> Result<Vec<u8>> {
+    let buffer: ImageBuffer<Rgba<u8>, _> = ImageBuffer::from_raw(width, height, data)
+        .context("Failed to create image buffer from raw pixel data")?;
+
+    let mut png_data = Vec::new();
+    let encoder = image::codecs::png::PngEncoder::new(&mut png_data);
+    buffer
+        .write_with_encoder(encoder)
+        .context("Failed to encode in-memory PNG for OCR processing")?;
+
+    Ok(png_data)
+}
+
+/// Spawns `tesseract stdin stdout <args>`, pipes `png_data` to its stdin, and
+/// returns the raw stdout bytes.
+///
+/// # Errors
+/// Returns an error if `tesseract` is missing from the PATH, or if the
+/// process exits with a non-zero status.
+fn run_tesseract(png_data: &[u8], args: &[&str]) -> Result<Vec<u8>> {
+    let mut child = Command::new("tesseract")
+        .arg("stdin")
+        .arg("stdout")
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .context("Tesseract process failed to start. Please ensure 'tesseract-ocr' is installed and in your PATH.")?;
+
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin
+            .write_all(png_data)
+            .context("Failed to pipe PNG data to Tesseract stdin")?;
+    }
+
+    let output = child
+        .wait_with_output()
+        .context("Failed to wait for Tesseract process execution")?;
+
+    if !output.status.success() {
+        let err_msg = String::from_utf8_lossy(&output.stderr);
+        anyhow::bail!("Tesseract execution failed with error: {}", err_msg);
+    }
+
+    Ok(output.stdout)
+}
+
+/// Parses Tesseract's `tsv` output format, keeping only word-level rows
+/// (`level == 5`) whose text is non-empty after trimming.
+///
+/// Columns are `level, page, block, par, line, word, left, top, width,
+/// height, conf, text`, tab-separated, with the first line being the
+/// header.
+fn parse_tsv_words(tsv: &[u8]) -> Result<Vec<OcrSegment>> {
+    let text = String::from_utf8_lossy(tsv);
+    let mut words = Vec::new();
+
+    for line in text.lines().skip(1) {
+        let cols: Vec<&str> = line.split('\t').collect();
+        if cols.len() < 12 {
+            continue;
         }
 
-        let output = child
-            .wait_with_output()
-            .context("Failed to wait for Tesseract process execution")?;
+        let level: i32 = cols[0].parse().unwrap_or(0);
+        if level != 5 {
+            continue;
+        }
 
-        if !output.status.success() {
-            let err_msg = String::from_utf8_lossy(&output.stderr);
-            anyhow::bail!("Tesseract execution failed with error: {}", err_msg);
+        let word_text = cols[11];
+        if word_text.trim().is_empty() {
+            continue;
         }
 
-        Ok(String::from_utf8_lossy(&output.stdout).to_string())
+        let left: i32 = cols[6].parse().unwrap_or(0);
+        let top: i32 = cols[7].parse().unwrap_or(0);
+        let width: i32 = cols[8].parse().unwrap_or(0);
+        let height: i32 = cols[9].parse().unwrap_or(0);
+        let conf: f32 = cols[10].parse().unwrap_or(-1.0);
+
+        words.push((
+            word_text.to_string(),
+            (left, top, left + width, top + height),
+            conf,
+        ));
     }
+
+    Ok(words)
 }